@@ -1,7 +1,9 @@
+mod auto_req;
+
 use crate::error::{ConfigError, Error};
 use cargo_toml::Error as CargoTomlError;
 use cargo_toml::Manifest;
-use rpm::{Compressor, RPMBuilder, RPMFileOptions};
+use rpm::{Compressor, Dependency, RPMBuilder, RPMFileOptions};
 use std::env::consts::ARCH;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
@@ -16,17 +18,56 @@ pub struct Config {
 }
 
 impl Config {
-    pub fn new(path: impl AsRef<Path>) -> Result<Self, Error> {
+    /// Loads the manifest at `path`. If it is a virtual workspace manifest (no `[package]`
+    /// table), resolves `package` to one of the workspace's members instead, erroring with
+    /// the list of available members when `package` is `None` and there is more than one.
+    pub fn new(path: impl AsRef<Path>, package: Option<&str>) -> Result<Self, Error> {
         let path = path.as_ref().to_path_buf();
-        Manifest::from_path(&path)
-            .map(|manifest| Config {
-                manifest,
-                path: path.clone(),
-            })
-            .map_err(|err| match err {
-                CargoTomlError::Io(e) => Error::FileIo(path, e),
-                _ => Error::CargoToml(err),
+        let manifest = Manifest::from_path(&path).map_err(|err| match err {
+            CargoTomlError::Io(e) => Error::FileIo(path.clone(), e),
+            _ => Error::CargoToml(err),
+        })?;
+
+        if manifest.package.is_some() {
+            return Ok(Config { manifest, path });
+        }
+
+        let workspace = manifest
+            .workspace
+            .as_ref()
+            .ok_or(ConfigError::Missing("package"))?;
+        let root = path.parent().unwrap_or_else(|| Path::new("."));
+        let members: Vec<(String, PathBuf)> = _expand_workspace_members(root, &workspace.members)
+            .into_iter()
+            .filter_map(|member_dir| {
+                let manifest_path = member_dir.join("Cargo.toml");
+                let member_manifest = Manifest::from_path(&manifest_path).ok()?;
+                let name = member_manifest.package?.name;
+                Some((name, manifest_path))
             })
+            .collect();
+
+        let manifest_path = match package {
+            Some(name) => members
+                .iter()
+                .find(|(member_name, _)| member_name == name)
+                .map(|(_, member_path)| member_path.clone())
+                .ok_or_else(|| {
+                    ConfigError::WorkspaceMemberNotFound(
+                        name.to_owned(),
+                        members.iter().map(|(n, _)| n.clone()).collect(),
+                    )
+                })?,
+            None => match members.as_slice() {
+                [(_, member_path)] => member_path.clone(),
+                _ => {
+                    return Err(Error::Config(ConfigError::WorkspacePackageRequired(
+                        members.into_iter().map(|(n, _)| n).collect(),
+                    )))
+                }
+            },
+        };
+        Self::new(manifest_path, None)
     }
 
     fn metadata(&self) -> Result<&Table, ConfigError> {
@@ -73,11 +114,61 @@ impl Config {
                 .clone();
             let info = _handle_file(table, idx)?;
 
-            files.push(info);
+            match &info.source {
+                AssetSource::Path(source) if _is_glob_pattern(source) => {
+                    files.extend(self._expand_glob_source(info, idx)?);
+                }
+                _ => files.push(info),
+            }
         }
         Ok(files)
     }
 
+    fn _expand_glob_source(
+        &self,
+        info: FileInfo,
+        idx: usize,
+    ) -> Result<Vec<FileInfo>, ConfigError> {
+        let source = match &info.source {
+            AssetSource::Path(source) => source.clone(),
+            AssetSource::Data(_) | AssetSource::Symlink(_) => {
+                unreachable!("only glob Path sources are expanded")
+            }
+        };
+        let candidates = [
+            PathBuf::from(&source),
+            self.path.parent().unwrap().join(&source),
+        ];
+
+        for pattern in &candidates {
+            let pattern_str = pattern.to_str().ok_or(ConfigError::AssetGlobInvalid(
+                idx,
+                "pattern is not valid UTF-8",
+            ))?;
+            let prefix = _glob_fixed_prefix(pattern_str);
+            let matches: Vec<PathBuf> = glob::glob(pattern_str)
+                .map_err(|_| ConfigError::AssetGlobInvalid(idx, "invalid glob pattern"))?
+                .filter_map(Result::ok)
+                .filter(|path| path.is_file())
+                .collect();
+            if matches.is_empty() {
+                continue;
+            }
+            return Ok(matches
+                .into_iter()
+                .map(|path| {
+                    let rel = path.strip_prefix(prefix).unwrap_or(path.as_path());
+                    FileInfo {
+                        dest: _join_dest_dir(&info.dest, rel),
+                        source: AssetSource::Path(path.to_string_lossy().into_owned()),
+                        ..info.clone()
+                    }
+                })
+                .collect());
+        }
+        Err(ConfigError::AssetFileNotFound(source))
+    }
+
     pub fn create_rpm_builder(&self, target_arch: Option<String>) -> Result<RPMBuilder, Error> {
         let metadata = self.metadata()?;
         macro_rules! get_str_from_metadata {
@@ -107,6 +198,20 @@ impl Config {
             }
         };
 
+        macro_rules! get_bool_from_metadata {
+            ($name:expr) => {
+                if let Some(val) = metadata.get($name) {
+                    Some(val.as_bool()
+                        .ok_or(ConfigError::WrongType(
+                            concat!("package.metadata.generate-rpm.", $name),
+                            "bool"
+                        ))?)
+                } else {
+                    None
+                } as Option<bool>
+            }
+        };
+
         let pkg = self
             .manifest
             .package
@@ -137,21 +242,64 @@ impl Config {
                 .as_str(),
         );
 
-        let mut builder = RPMBuilder::new(name, version, license, arch.as_str(), desc)
-            .compression(Compressor::from_str("gzip").unwrap());
+        let compression_name = get_str_from_metadata!("compression").unwrap_or("gzip");
+        let compression = Compressor::from_str(compression_name).map_err(|_| {
+            ConfigError::WrongType("compression", "\"gzip\", \"zstd\", \"xz\" or \"none\"")
+        })?;
+
+        let mut builder =
+            RPMBuilder::new(name, version, license, arch.as_str(), desc).compression(compression);
+        let auto_req = get_bool_from_metadata!("auto_req").unwrap_or(false);
+        let mut auto_req_sonames = std::collections::BTreeSet::new();
         for file in &self.files()? {
             let options = file.generate_rpm_file_options();
 
-            let file_source = [
-                PathBuf::from(&file.source),
-                self.path.parent().unwrap().join(&file.source),
-            ]
-            .iter()
-            .find(|v| v.exists())
-            .ok_or(ConfigError::AssetFileNotFound(file.source.to_string()))?
-            .to_owned();
+            builder = match &file.source {
+                AssetSource::Path(source) => {
+                    let file_source = [
+                        PathBuf::from(source),
+                        self.path.parent().unwrap().join(source),
+                    ]
+                    .iter()
+                    .find(|v| v.exists())
+                    .ok_or(ConfigError::AssetFileNotFound(source.to_string()))?
+                    .to_owned();
+
+                    if auto_req {
+                        if let Some(needed) = auto_req::elf_needed(&file_source)? {
+                            for soname in needed.sonames {
+                                auto_req_sonames
+                                    .insert(_format_auto_req_soname(soname, needed.is_64bit));
+                            }
+                        }
+                    }
+
+                    builder.with_file(file_source, options)?
+                }
+                AssetSource::Data(content) => {
+                    builder.with_file_contents(content.clone().into_bytes(), options)?
+                }
+                AssetSource::Symlink(target) => {
+                    builder.with_file_contents(target.clone().into_bytes(), options)?
+                }
+            };
+        }
 
-            builder = builder.with_file(file_source, options)?;
+        for soname in auto_req_sonames {
+            builder = builder.requires(Dependency::any(soname));
+        }
+
+        for dependency in _get_dependencies(metadata, "requires")? {
+            builder = builder.requires(dependency);
+        }
+        for dependency in _get_dependencies(metadata, "provides")? {
+            builder = builder.provides(dependency);
+        }
+        for dependency in _get_dependencies(metadata, "conflicts")? {
+            builder = builder.conflicts(dependency);
+        }
+        for dependency in _get_dependencies(metadata, "obsoletes")? {
+            builder = builder.obsoletes(dependency);
         }
 
         if let Some(release) = get_i64_from_metadata!("release") {
@@ -179,7 +327,7 @@ impl Config {
 }
 
 fn _handle_file(table: ConfigTable, idx: usize) -> Result<FileInfo, ConfigError> {
-    let source = _get_source(&table, idx)?;
+    let source = _get_asset_source(&table, idx)?;
     let dest = _get_dest(&table, idx)?;
 
     let user = _get_user(&table, idx)?;
@@ -200,13 +348,147 @@ fn _handle_file(table: ConfigTable, idx: usize) -> Result<FileInfo, ConfigError>
     Ok(info)
 }
 
-fn _get_source(table: &ConfigTable, idx: usize) -> Result<String, ConfigError> {
-    Ok(table
-        .get("source")
-        .ok_or(ConfigError::AssetFileUndefined(idx, "source"))?
-        .as_str()
-        .ok_or(ConfigError::AssetFileWrongType(idx, "source", "string"))?
-        .to_owned())
+/// Expands a workspace's `members` glob patterns (e.g. `"crates/*"`) into member directories.
+fn _expand_workspace_members(root: &Path, patterns: &[String]) -> Vec<PathBuf> {
+    let mut members = Vec::new();
+    for pattern in patterns {
+        let pattern_path = root.join(pattern);
+        let pattern_str = match pattern_path.to_str() {
+            Some(s) => s,
+            None => continue,
+        };
+        if let Ok(paths) = glob::glob(pattern_str) {
+            members.extend(paths.filter_map(Result::ok).filter(|p| p.is_dir()));
+        }
+    }
+    members
+}
+
+fn _get_dependencies(metadata: &Table, key: &'static str) -> Result<Vec<Dependency>, ConfigError> {
+    let value = match metadata.get(key) {
+        Some(value) => value,
+        None => return Ok(Vec::new()),
+    };
+    if let Some(array) = value.as_array() {
+        array
+            .iter()
+            .map(|v| {
+                let spec = v.as_str().ok_or(ConfigError::WrongType(key, "string"))?;
+                let (name, constraint) = _split_name_constraint(spec);
+                _dependency_from_constraint(name, constraint.unwrap_or(""), key)
+            })
+            .collect()
+    } else if let Some(table) = value.as_table() {
+        table
+            .iter()
+            .map(|(name, v)| {
+                let constraint = v.as_str().ok_or(ConfigError::WrongType(key, "string"))?;
+                _dependency_from_constraint(name, constraint, key)
+            })
+            .collect()
+    } else {
+        Err(ConfigError::WrongType(key, "array or table"))
+    }
+}
+
+/// Splits `"glibc >= 2.17"` into `("glibc", Some(">= 2.17"))`, or `"glibc"` into `("glibc", None)`.
+fn _split_name_constraint(spec: &str) -> (&str, Option<&str>) {
+    match spec.find(['<', '>', '=']) {
+        Some(pos) => (spec[..pos].trim(), Some(spec[pos..].trim())),
+        None => (spec.trim(), None),
+    }
+}
+
+fn _dependency_from_constraint(
+    name: &str,
+    constraint: &str,
+    key: &'static str,
+) -> Result<Dependency, ConfigError> {
+    let constraint = constraint.trim();
+    if constraint.is_empty() {
+        if name.split_whitespace().count() > 1 {
+            return Err(ConfigError::WrongType(
+                key,
+                "version constraint (e.g. \">= 1.0\")",
+            ));
+        }
+        return Ok(Dependency::any(name));
+    }
+    if let Some(version) = constraint.strip_prefix(">=") {
+        Ok(Dependency::greater_eq(name, version.trim()))
+    } else if let Some(version) = constraint.strip_prefix("<=") {
+        Ok(Dependency::less_eq(name, version.trim()))
+    } else if let Some(version) = constraint.strip_prefix('=') {
+        Ok(Dependency::eq(name, version.trim()))
+    } else if let Some(version) = constraint.strip_prefix('>') {
+        Ok(Dependency::greater(name, version.trim()))
+    } else if let Some(version) = constraint.strip_prefix('<') {
+        Ok(Dependency::less(name, version.trim()))
+    } else {
+        Err(ConfigError::WrongType(
+            key,
+            "version constraint (e.g. \">= 1.0\")",
+        ))
+    }
+}
+
+/// Formats an `auto_req`-discovered soname as an RPM `Requires` capability, appending the
+/// `()(64bit)` suffix rpm uses to distinguish multilib library dependencies on 64-bit objects
+/// (e.g. `libssl.so.3()(64bit)`); 32-bit sonames are returned unchanged.
+fn _format_auto_req_soname(soname: String, is_64bit: bool) -> String {
+    if is_64bit {
+        format!("{}()(64bit)", soname)
+    } else {
+        soname
+    }
+}
+
+fn _is_glob_pattern(source: &str) -> bool {
+    source.contains(['*', '?', '[', ']'])
+}
+
+/// The directory portion of a glob pattern that precedes its first metacharacter,
+/// used to turn each matched path into a `dest`-relative path.
+fn _glob_fixed_prefix(pattern: &str) -> &str {
+    let cut = pattern.find(['*', '?', '[', ']']).unwrap_or(pattern.len());
+    match pattern[..cut].rfind('/') {
+        Some(slash) => &pattern[..slash],
+        None => "",
+    }
+}
+
+fn _join_dest_dir(dest: &str, rel: &Path) -> String {
+    format!("{}/{}", dest.trim_end_matches('/'), rel.display())
+}
+
+fn _get_asset_source(table: &ConfigTable, idx: usize) -> Result<AssetSource, ConfigError> {
+    match (
+        table.get("source"),
+        table.get("content"),
+        table.get("symlink"),
+    ) {
+        (Some(v), None, None) => Ok(AssetSource::Path(
+            v.as_str()
+                .ok_or(ConfigError::AssetFileWrongType(idx, "source", "string"))?
+                .to_owned(),
+        )),
+        (None, Some(v), None) => Ok(AssetSource::Data(
+            v.as_str()
+                .ok_or(ConfigError::AssetFileWrongType(idx, "content", "string"))?
+                .to_owned(),
+        )),
+        (None, None, Some(v)) => Ok(AssetSource::Symlink(
+            v.as_str()
+                .ok_or(ConfigError::AssetFileWrongType(idx, "symlink", "string"))?
+                .to_owned(),
+        )),
+        (None, None, None) => Err(ConfigError::AssetFileUndefined(idx, "source")),
+        _ => Err(ConfigError::AssetFileWrongType(
+            idx,
+            "source",
+            "exactly one of source, content, or symlink",
+        )),
+    }
 }
 
 fn _get_dest(table: &ConfigTable, idx: usize) -> Result<String, ConfigError> {
@@ -243,7 +525,11 @@ fn _get_group(table: &ConfigTable, idx: usize) -> Result<Option<String>, ConfigE
     }
 }
 
-fn _get_mode(table: &ConfigTable, source: &str, idx: usize) -> Result<Option<usize>, ConfigError> {
+fn _get_mode(
+    table: &ConfigTable,
+    source: &AssetSource,
+    idx: usize,
+) -> Result<Option<usize>, ConfigError> {
     if let Some(mode) = table.get("mode") {
         let mode = mode
             .as_str()
@@ -252,10 +538,11 @@ fn _get_mode(table: &ConfigTable, source: &str, idx: usize) -> Result<Option<usi
             .map_err(|_| ConfigError::AssetFileWrongType(idx, "mode", "oct-string"))?;
         let file_mode = if mode & 0o170000 != 0 {
             None
-        } else if source.ends_with('/') {
-            Some(0o040000) // S_IFDIR
         } else {
-            Some(0o100000) // S_IFREG
+            match source {
+                AssetSource::Path(path) if path.ends_with('/') => Some(0o040000), // S_IFDIR
+                _ => Some(0o100000),                                              // S_IFREG
+            }
         };
         Ok(Some(file_mode.unwrap_or_default() | mode))
     } else {
@@ -283,9 +570,18 @@ fn _get_doc(table: &ConfigTable, idx: usize) -> Result<bool, ConfigError> {
     }
 }
 
+/// Where an asset's bytes come from, mirroring cargo-deb's `AssetSource`: an on-disk file,
+/// inline content written verbatim, or a symlink packaged with the given target.
+#[derive(Debug, Eq, PartialEq, Clone)]
+enum AssetSource {
+    Path(String),
+    Data(String),
+    Symlink(String),
+}
+
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct FileInfo {
-    source: String,
+    source: AssetSource,
     dest: String,
     user: Option<String>,
     group: Option<String>,
@@ -303,7 +599,15 @@ impl FileInfo {
         if let Some(group) = &self.group {
             rpm_file_option = rpm_file_option.group(group);
         }
-        if let Some(mode) = self.mode {
+        // In the rpm/cpio payload a symlink is a regular entry whose content is the link
+        // target and whose mode carries S_IFLNK, so the byte content written for
+        // `AssetSource::Symlink` (see `create_rpm_builder`) must be paired with this mode.
+        let mode = match (&self.source, self.mode) {
+            (AssetSource::Symlink(_), Some(mode)) => Some((mode & !0o170000) | 0o120000),
+            (AssetSource::Symlink(_), None) => Some(0o120000 | 0o777), // S_IFLNK
+            (_, mode) => mode,
+        };
+        if let Some(mode) = mode {
             rpm_file_option = rpm_file_option.mode(mode as i32);
         }
         if self.config {
@@ -322,21 +626,81 @@ mod test {
 
     #[test]
     fn test_config_new() {
-        let config = Config::new("Cargo.toml").unwrap();
+        let config = Config::new("Cargo.toml", None).unwrap();
         let pkg = config.manifest.package.unwrap();
         assert_eq!(pkg.name, "cargo-generate-rpm");
 
-        assert!(matches!(Config::new("not_exist_path/Cargo.toml"),
+        assert!(matches!(Config::new("not_exist_path/Cargo.toml", None),
             Err(Error::FileIo(path, error)) if path == PathBuf::from("not_exist_path/Cargo.toml") && error.kind() == std::io::ErrorKind::NotFound));
         assert!(matches!(
-            Config::new("src/error.rs"),
+            Config::new("src/error.rs", None),
             Err(Error::CargoToml(_))
         ));
     }
 
+    #[test]
+    fn test_config_new_resolves_workspace_members() {
+        let dir = std::env::temp_dir().join(format!(
+            "cargo-generate-rpm-test-workspace-{}",
+            std::process::id()
+        ));
+        let write_member = |name: &str| {
+            let member_dir = dir.join(name);
+            std::fs::create_dir_all(&member_dir).unwrap();
+            std::fs::write(
+                member_dir.join("Cargo.toml"),
+                format!(
+                    r#"
+                    [package]
+                    name = "{name}"
+                    version = "1.0.0"
+                    license = "MIT"
+                    description = "test"
+
+                    [package.metadata.generate-rpm]
+                    assets = []
+                    "#,
+                ),
+            )
+            .unwrap();
+        };
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("Cargo.toml"),
+            r#"
+            [workspace]
+            members = ["member-a", "member-b"]
+            "#,
+        )
+        .unwrap();
+        write_member("member-a");
+        write_member("member-b");
+
+        let root = dir.join("Cargo.toml");
+
+        assert!(matches!(
+            Config::new(&root, None),
+            Err(Error::Config(ConfigError::WorkspacePackageRequired(members)))
+                if members.len() == 2
+                    && members.contains(&"member-a".to_owned())
+                    && members.contains(&"member-b".to_owned())
+        ));
+
+        let config = Config::new(&root, Some("member-a")).unwrap();
+        assert_eq!(config.manifest.package.unwrap().name, "member-a");
+
+        assert!(matches!(
+            Config::new(&root, Some("nope")),
+            Err(Error::Config(ConfigError::WorkspaceMemberNotFound(name, members)))
+                if name == "nope" && members.len() == 2
+        ));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
     #[test]
     fn test_metadata() {
-        let config = Config::new("Cargo.toml").unwrap();
+        let config = Config::new("Cargo.toml", None).unwrap();
         let metadata = config.metadata().unwrap();
         let assets = metadata.get("assets").unwrap();
         assert!(assets.is_array());
@@ -344,13 +708,13 @@ mod test {
 
     #[test]
     fn test_files() {
-        let config = Config::new("Cargo.toml").unwrap();
+        let config = Config::new("Cargo.toml", None).unwrap();
         let files = config.files().unwrap();
         assert_eq!(
             files,
             vec![
                 FileInfo {
-                    source: "target/release/cargo-generate-rpm".to_owned(),
+                    source: AssetSource::Path("target/release/cargo-generate-rpm".to_owned()),
                     dest: "/usr/bin/cargo-generate-rpm".to_owned(),
                     user: None,
                     group: None,
@@ -359,7 +723,7 @@ mod test {
                     doc: false
                 },
                 FileInfo {
-                    source: "LICENSE".to_owned(),
+                    source: AssetSource::Path("LICENSE".to_owned()),
                     dest: "/usr/share/doc/cargo-generate-rpm/LICENSE".to_owned(),
                     user: None,
                     group: None,
@@ -368,7 +732,7 @@ mod test {
                     doc: true
                 },
                 FileInfo {
-                    source: "README.md".to_owned(),
+                    source: AssetSource::Path("README.md".to_owned()),
                     dest: "/usr/share/doc/cargo-generate-rpm/README.md".to_owned(),
                     user: None,
                     group: None,
@@ -382,7 +746,7 @@ mod test {
 
     #[test]
     fn test_config_create_rpm_builder() {
-        let config = Config::new("Cargo.toml").unwrap();
+        let config = Config::new("Cargo.toml", None).unwrap();
         let builder = config.create_rpm_builder(None);
 
         assert!(if Path::new("target/release/cargo-generate-rpm").exists() {
@@ -391,4 +755,162 @@ mod test {
             matches!(builder, Err(Error::Config(ConfigError::AssetFileNotFound(path))) if path == "target/release/cargo-generate-rpm")
         });
     }
+
+    #[test]
+    fn test_symlink_asset_round_trips_as_symlink() {
+        let info = FileInfo {
+            source: AssetSource::Symlink("/usr/lib/libfoo.so".to_owned()),
+            dest: "/usr/lib/libfoo.so.1".to_owned(),
+            user: None,
+            group: None,
+            mode: None,
+            config: false,
+            doc: false,
+        };
+        let options = info.generate_rpm_file_options();
+
+        let pkg = RPMBuilder::new("test", "1.0.0", "MIT", "x86_64", "test")
+            .with_file_contents(b"/usr/lib/libfoo.so".to_vec(), options)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut buf = Vec::new();
+        pkg.write(&mut buf).unwrap();
+        let parsed = rpm::RPMPackage::parse(&mut std::io::Cursor::new(buf)).unwrap();
+
+        let entry = parsed
+            .metadata
+            .get_file_entries()
+            .unwrap()
+            .into_iter()
+            .find(|e| e.path.to_string_lossy() == "/usr/lib/libfoo.so.1")
+            .expect("symlink entry present in packaged file list");
+
+        assert_eq!(
+            entry.mode.permissions().bits() as usize & 0o170000,
+            0o120000,
+            "packaged entry must carry S_IFLNK, not just look like a regular file"
+        );
+        assert_eq!(entry.linkto, "/usr/lib/libfoo.so");
+    }
+
+    /// Builds a `Config` from an in-memory manifest (no `assets` entries, so
+    /// `create_rpm_builder` never has to touch the filesystem for files) with the given
+    /// extra lines spliced into `package.metadata.generate-rpm`.
+    fn _test_config_with_metadata(extra_metadata: &str) -> Config {
+        let toml = format!(
+            r#"
+            [package]
+            name = "test"
+            version = "1.0.0"
+            license = "MIT"
+            description = "test"
+
+            [package.metadata.generate-rpm]
+            assets = []
+            {extra_metadata}
+            "#,
+        );
+        Config {
+            manifest: Manifest::from_str(&toml).unwrap(),
+            path: PathBuf::from("Cargo.toml"),
+        }
+    }
+
+    #[test]
+    fn test_compression_metadata_is_wired_into_the_builder() {
+        let config = _test_config_with_metadata("");
+        assert!(
+            config.create_rpm_builder(None).is_ok(),
+            "missing compression key should default to gzip, not error"
+        );
+
+        let config = _test_config_with_metadata(r#"compression = "zstd""#);
+        assert!(
+            config.create_rpm_builder(None).is_ok(),
+            "zstd is a documented-valid compression value"
+        );
+
+        let config = _test_config_with_metadata(r#"compression = "bogus""#);
+        assert!(matches!(
+            config.create_rpm_builder(None),
+            Err(Error::Config(ConfigError::WrongType("compression", _)))
+        ));
+    }
+
+    #[test]
+    fn test_expand_glob_source() {
+        let dir = std::env::temp_dir().join(format!(
+            "cargo-generate-rpm-test-glob-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("a.so"), b"").unwrap();
+        std::fs::write(dir.join("b.so"), b"").unwrap();
+        std::fs::write(dir.join("c.txt"), b"").unwrap();
+
+        let config = _test_config_with_metadata("");
+        let make_info = |pattern: String| FileInfo {
+            source: AssetSource::Path(pattern),
+            dest: "/usr/lib/foo/".to_owned(),
+            user: None,
+            group: None,
+            mode: None,
+            config: false,
+            doc: false,
+        };
+
+        let pattern = dir.join("*.so").to_string_lossy().into_owned();
+        let mut dests: Vec<String> = config
+            ._expand_glob_source(make_info(pattern), 0)
+            .unwrap()
+            .into_iter()
+            .map(|info| info.dest)
+            .collect();
+        dests.sort();
+        assert_eq!(
+            dests,
+            vec![
+                "/usr/lib/foo/a.so".to_owned(),
+                "/usr/lib/foo/b.so".to_owned()
+            ]
+        );
+
+        let empty_pattern = dir.join("*.nonexistent").to_string_lossy().into_owned();
+        assert!(matches!(
+            config._expand_glob_source(make_info(empty_pattern), 0),
+            Err(ConfigError::AssetFileNotFound(_))
+        ));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_format_auto_req_soname() {
+        assert_eq!(
+            _format_auto_req_soname("libssl.so.3".to_owned(), true),
+            "libssl.so.3()(64bit)"
+        );
+        assert_eq!(
+            _format_auto_req_soname("libssl.so.3".to_owned(), false),
+            "libssl.so.3"
+        );
+    }
+
+    #[test]
+    fn test_dependency_from_constraint() {
+        assert!(matches!(
+            _dependency_from_constraint("glibc", ">= 2.17", "requires"),
+            Ok(_)
+        ));
+        assert!(matches!(
+            _dependency_from_constraint("glibc", "", "requires"),
+            Ok(_)
+        ));
+        assert!(matches!(
+            _dependency_from_constraint("foo ~ 1.0", "", "requires"),
+            Err(ConfigError::WrongType("requires", _))
+        ));
+    }
 }
@@ -0,0 +1,387 @@
+use crate::error::ConfigError;
+use std::fs;
+use std::path::Path;
+
+const ELF_MAGIC: [u8; 4] = [0x7f, b'E', b'L', b'F'];
+const SHT_DYNAMIC: u32 = 6;
+const DT_NULL: i64 = 0;
+const DT_NEEDED: i64 = 1;
+
+/// Shared library dependencies discovered by scanning an ELF asset's dynamic section.
+pub struct ElfNeeded {
+    pub sonames: Vec<String>,
+    pub is_64bit: bool,
+}
+
+/// Reads `path` and, if it is an ELF binary, returns the `DT_NEEDED` sonames from its
+/// dynamic section. Returns `Ok(None)` for non-ELF files and statically linked binaries,
+/// which `auto_req` should skip rather than fail on.
+pub fn elf_needed(path: &Path) -> Result<Option<ElfNeeded>, ConfigError> {
+    let data = fs::read(path)
+        .map_err(|_| ConfigError::AssetReadFailed("failed to read asset file for auto_req scan"))?;
+    if data.len() < 20 || data[0..4] != ELF_MAGIC {
+        return Ok(None);
+    }
+    let is_64 = match data[4] {
+        1 => false,
+        2 => true,
+        _ => return Ok(None),
+    };
+    let le = match data[5] {
+        1 => true,
+        2 => false,
+        _ => return Ok(None),
+    };
+
+    let sonames = if is_64 {
+        _parse_dynamic_needed_64(&data, le)
+    } else {
+        _parse_dynamic_needed_32(&data, le)
+    };
+
+    Ok(sonames.map(|sonames| ElfNeeded {
+        sonames,
+        is_64bit: is_64,
+    }))
+}
+
+fn _read_u16(data: &[u8], offset: usize, le: bool) -> Option<u16> {
+    let bytes: [u8; 2] = data.get(offset..offset + 2)?.try_into().ok()?;
+    Some(if le {
+        u16::from_le_bytes(bytes)
+    } else {
+        u16::from_be_bytes(bytes)
+    })
+}
+
+fn _read_u32(data: &[u8], offset: usize, le: bool) -> Option<u32> {
+    let bytes: [u8; 4] = data.get(offset..offset + 4)?.try_into().ok()?;
+    Some(if le {
+        u32::from_le_bytes(bytes)
+    } else {
+        u32::from_be_bytes(bytes)
+    })
+}
+
+fn _read_u64(data: &[u8], offset: usize, le: bool) -> Option<u64> {
+    let bytes: [u8; 8] = data.get(offset..offset + 8)?.try_into().ok()?;
+    Some(if le {
+        u64::from_le_bytes(bytes)
+    } else {
+        u64::from_be_bytes(bytes)
+    })
+}
+
+fn _read_cstr(data: &[u8], offset: usize) -> Option<String> {
+    let slice = data.get(offset..)?;
+    let end = slice.iter().position(|&b| b == 0)?;
+    Some(String::from_utf8_lossy(&slice[..end]).into_owned())
+}
+
+/// Finds the `.dynamic` section and its linked string table, returning `DT_NEEDED` sonames.
+/// Returns `None` if the binary has no dynamic section at all (i.e. it's statically linked)
+/// or the section headers are malformed.
+fn _parse_dynamic_needed_64(data: &[u8], le: bool) -> Option<Vec<String>> {
+    let e_shoff = _read_u64(data, 0x28, le)? as usize;
+    let e_shentsize = _read_u16(data, 0x3a, le)? as usize;
+    let e_shnum = _read_u16(data, 0x3c, le)? as usize;
+
+    for i in 0..e_shnum {
+        let sh = e_shoff + i * e_shentsize;
+        let sh_type = _read_u32(data, sh + 4, le)?;
+        if sh_type != SHT_DYNAMIC {
+            continue;
+        }
+        let sh_offset = _read_u64(data, sh + 24, le)? as usize;
+        let sh_size = _read_u64(data, sh + 32, le)? as usize;
+        let sh_link = _read_u32(data, sh + 40, le)? as usize;
+
+        let strtab_sh = e_shoff + sh_link * e_shentsize;
+        let str_offset = _read_u64(data, strtab_sh + 24, le)? as usize;
+
+        let mut sonames = Vec::new();
+        let mut off = sh_offset;
+        while off + 16 <= sh_offset + sh_size {
+            let d_tag = _read_u64(data, off, le)? as i64;
+            let d_val = _read_u64(data, off + 8, le)?;
+            if d_tag == DT_NULL {
+                break;
+            }
+            if d_tag == DT_NEEDED {
+                if let Some(name) = _read_cstr(data, str_offset + d_val as usize) {
+                    sonames.push(name);
+                }
+            }
+            off += 16;
+        }
+        return Some(sonames);
+    }
+    None
+}
+
+fn _parse_dynamic_needed_32(data: &[u8], le: bool) -> Option<Vec<String>> {
+    let e_shoff = _read_u32(data, 0x20, le)? as usize;
+    let e_shentsize = _read_u16(data, 0x2e, le)? as usize;
+    let e_shnum = _read_u16(data, 0x30, le)? as usize;
+
+    for i in 0..e_shnum {
+        let sh = e_shoff + i * e_shentsize;
+        let sh_type = _read_u32(data, sh + 4, le)?;
+        if sh_type != SHT_DYNAMIC {
+            continue;
+        }
+        let sh_offset = _read_u32(data, sh + 16, le)? as usize;
+        let sh_size = _read_u32(data, sh + 20, le)? as usize;
+        let sh_link = _read_u32(data, sh + 24, le)? as usize;
+
+        let strtab_sh = e_shoff + sh_link * e_shentsize;
+        let str_offset = _read_u32(data, strtab_sh + 16, le)? as usize;
+
+        let mut sonames = Vec::new();
+        let mut off = sh_offset;
+        while off + 8 <= sh_offset + sh_size {
+            let d_tag = _read_u32(data, off, le)? as i64;
+            let d_val = _read_u32(data, off + 4, le)?;
+            if d_tag == DT_NULL {
+                break;
+            }
+            if d_tag == DT_NEEDED {
+                if let Some(name) = _read_cstr(data, str_offset + d_val as usize) {
+                    sonames.push(name);
+                }
+            }
+            off += 8;
+        }
+        return Some(sonames);
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::Write;
+
+    /// Builds a minimal ELF64 image with one `SHT_DYNAMIC` section (a `DT_NEEDED` entry
+    /// followed by `DT_NULL`) and the string table it links to, laid out as:
+    /// header, dynamic entries, string table, section header table.
+    fn _build_elf64(le: bool, soname: &str) -> Vec<u8> {
+        let put_u16 = |buf: &mut Vec<u8>, v: u16| {
+            buf.extend_from_slice(&if le { v.to_le_bytes() } else { v.to_be_bytes() });
+        };
+        let put_u32 = |buf: &mut Vec<u8>, v: u32| {
+            buf.extend_from_slice(&if le { v.to_le_bytes() } else { v.to_be_bytes() });
+        };
+        let put_u64 = |buf: &mut Vec<u8>, v: u64| {
+            buf.extend_from_slice(&if le { v.to_le_bytes() } else { v.to_be_bytes() });
+        };
+
+        let dynamic_off = 64usize;
+        let dynamic: Vec<u8> = {
+            let mut d = Vec::new();
+            put_u64(&mut d, DT_NEEDED as u64);
+            put_u64(&mut d, 1); // d_val: offset of `soname` within the string table
+            put_u64(&mut d, DT_NULL as u64);
+            put_u64(&mut d, 0);
+            d
+        };
+        let strtab_off = dynamic_off + dynamic.len();
+        let mut strtab = vec![0u8]; // conventional leading NUL entry
+        strtab.write_all(soname.as_bytes()).unwrap();
+        strtab.push(0);
+        let shoff = strtab_off + strtab.len();
+        let shentsize = 64usize;
+
+        let mut data = vec![0u8; 64];
+        data[0..4].copy_from_slice(&ELF_MAGIC);
+        data[4] = 2; // ELFCLASS64
+        data[5] = if le { 1 } else { 2 };
+        {
+            let mut tail = Vec::new();
+            put_u64(&mut tail, shoff as u64); // e_shoff @ 0x28
+            data[0x28..0x28 + 8].copy_from_slice(&tail);
+        }
+        {
+            let mut tail = Vec::new();
+            put_u16(&mut tail, shentsize as u16); // e_shentsize @ 0x3a
+            data[0x3a..0x3a + 2].copy_from_slice(&tail);
+        }
+        {
+            let mut tail = Vec::new();
+            put_u16(&mut tail, 3); // e_shnum @ 0x3c: null, dynamic, strtab
+            data[0x3c..0x3c + 2].copy_from_slice(&tail);
+        }
+        data.extend_from_slice(&dynamic);
+        data.extend_from_slice(&strtab);
+
+        // Section 0: SHT_NULL, all zero.
+        data.resize(shoff + shentsize, 0);
+        // Section 1: the SHT_DYNAMIC section.
+        let sh1 = shoff + shentsize;
+        data.resize(sh1 + shentsize, 0);
+        {
+            let mut field = Vec::new();
+            put_u32(&mut field, SHT_DYNAMIC);
+            data[sh1 + 4..sh1 + 8].copy_from_slice(&field);
+        }
+        {
+            let mut field = Vec::new();
+            put_u64(&mut field, dynamic_off as u64);
+            data[sh1 + 24..sh1 + 32].copy_from_slice(&field);
+        }
+        {
+            let mut field = Vec::new();
+            put_u64(&mut field, dynamic.len() as u64);
+            data[sh1 + 32..sh1 + 40].copy_from_slice(&field);
+        }
+        {
+            let mut field = Vec::new();
+            put_u32(&mut field, 2); // sh_link -> section 2 (strtab)
+            data[sh1 + 40..sh1 + 44].copy_from_slice(&field);
+        }
+        // Section 2: the string table section.
+        let sh2 = sh1 + shentsize;
+        data.resize(sh2 + shentsize, 0);
+        {
+            let mut field = Vec::new();
+            put_u64(&mut field, strtab_off as u64);
+            data[sh2 + 24..sh2 + 32].copy_from_slice(&field);
+        }
+
+        data
+    }
+
+    /// Mirrors `_build_elf64` for the ELF32 layout (`Shdr` fields are 32-bit and packed
+    /// differently, so the offsets differ from the 64-bit builder above).
+    fn _build_elf32(le: bool, soname: &str) -> Vec<u8> {
+        let put_u16 = |buf: &mut Vec<u8>, v: u16| {
+            buf.extend_from_slice(&if le { v.to_le_bytes() } else { v.to_be_bytes() });
+        };
+        let put_u32 = |buf: &mut Vec<u8>, v: u32| {
+            buf.extend_from_slice(&if le { v.to_le_bytes() } else { v.to_be_bytes() });
+        };
+
+        let dynamic_off = 52usize;
+        let dynamic: Vec<u8> = {
+            let mut d = Vec::new();
+            put_u32(&mut d, DT_NEEDED as u32);
+            put_u32(&mut d, 1);
+            put_u32(&mut d, DT_NULL as u32);
+            put_u32(&mut d, 0);
+            d
+        };
+        let strtab_off = dynamic_off + dynamic.len();
+        let mut strtab = vec![0u8];
+        strtab.write_all(soname.as_bytes()).unwrap();
+        strtab.push(0);
+        let shoff = strtab_off + strtab.len();
+        let shentsize = 40usize;
+
+        let mut data = vec![0u8; 52];
+        data[0..4].copy_from_slice(&ELF_MAGIC);
+        data[4] = 1; // ELFCLASS32
+        data[5] = if le { 1 } else { 2 };
+        {
+            let mut field = Vec::new();
+            put_u32(&mut field, shoff as u32); // e_shoff @ 0x20
+            data[0x20..0x20 + 4].copy_from_slice(&field);
+        }
+        {
+            let mut field = Vec::new();
+            put_u16(&mut field, shentsize as u16); // e_shentsize @ 0x2e
+            data[0x2e..0x2e + 2].copy_from_slice(&field);
+        }
+        {
+            let mut field = Vec::new();
+            put_u16(&mut field, 3); // e_shnum @ 0x30
+            data[0x30..0x30 + 2].copy_from_slice(&field);
+        }
+        data.extend_from_slice(&dynamic);
+        data.extend_from_slice(&strtab);
+
+        data.resize(shoff + shentsize, 0); // section 0: SHT_NULL
+        let sh1 = shoff + shentsize;
+        data.resize(sh1 + shentsize, 0);
+        {
+            let mut field = Vec::new();
+            put_u32(&mut field, SHT_DYNAMIC);
+            data[sh1 + 4..sh1 + 8].copy_from_slice(&field);
+        }
+        {
+            let mut field = Vec::new();
+            put_u32(&mut field, dynamic_off as u32);
+            data[sh1 + 16..sh1 + 20].copy_from_slice(&field);
+        }
+        {
+            let mut field = Vec::new();
+            put_u32(&mut field, dynamic.len() as u32);
+            data[sh1 + 20..sh1 + 24].copy_from_slice(&field);
+        }
+        {
+            let mut field = Vec::new();
+            put_u32(&mut field, 2); // sh_link -> section 2 (strtab)
+            data[sh1 + 24..sh1 + 28].copy_from_slice(&field);
+        }
+        let sh2 = sh1 + shentsize;
+        data.resize(sh2 + shentsize, 0);
+        {
+            let mut field = Vec::new();
+            put_u32(&mut field, strtab_off as u32);
+            data[sh2 + 16..sh2 + 20].copy_from_slice(&field);
+        }
+
+        data
+    }
+
+    fn _write_fixture(name: &str, bytes: &[u8]) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "cargo-generate-rpm-test-elf-{}-{}",
+            std::process::id(),
+            name
+        ));
+        fs::write(&path, bytes).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_elf_needed_64bit_little_endian() {
+        let path = _write_fixture("64le", &_build_elf64(true, "libssl.so.3"));
+        let needed = elf_needed(&path)
+            .unwrap()
+            .expect("ELF64 LE should be recognized");
+        assert!(needed.is_64bit);
+        assert_eq!(needed.sonames, vec!["libssl.so.3".to_owned()]);
+        fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_elf_needed_32bit_big_endian() {
+        let path = _write_fixture("32be", &_build_elf32(false, "libc.so.6"));
+        let needed = elf_needed(&path)
+            .unwrap()
+            .expect("ELF32 BE should be recognized");
+        assert!(!needed.is_64bit);
+        assert_eq!(needed.sonames, vec!["libc.so.6".to_owned()]);
+        fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_elf_needed_skips_non_elf_file() {
+        let path = _write_fixture("not-elf", b"not an elf file at all");
+        assert!(elf_needed(&path).unwrap().is_none());
+        fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_elf_needed_skips_statically_linked_binary() {
+        // A well-formed ELF64 header with no section headers at all: nothing to scan,
+        // same as a statically linked binary with no dynamic section.
+        let mut data = vec![0u8; 64];
+        data[0..4].copy_from_slice(&ELF_MAGIC);
+        data[4] = 2; // ELFCLASS64
+        data[5] = 1; // little-endian
+        let path = _write_fixture("static", &data);
+        assert!(elf_needed(&path).unwrap().is_none());
+        fs::remove_file(&path).ok();
+    }
+}
@@ -19,6 +19,10 @@ pub enum ConfigError {
     AssetGlobInvalid(usize, &'static str),
     #[error("File unreadable: {0}")]
     AssetReadFailed(&'static str),
+    #[error("--package is required in this workspace, available members: {0:?}")]
+    WorkspacePackageRequired(Vec<String>),
+    #[error("package {0} not found in workspace, available members: {1:?}")]
+    WorkspaceMemberNotFound(String, Vec<String>),
 }
 
 #[derive(thiserror::Error, Debug)]
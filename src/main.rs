@@ -0,0 +1,103 @@
+mod config;
+mod error;
+
+use config::Config;
+use error::{ConfigError, Error};
+use std::env;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::process::exit;
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("cargo-generate-rpm: {}", err);
+        exit(1);
+    }
+}
+
+struct Args {
+    manifest_path: PathBuf,
+    package: Option<String>,
+    target_arch: Option<String>,
+}
+
+/// Parses CLI args, following the pattern cargo-fmt uses: `--manifest-path <path>` selects
+/// the manifest to read (defaulting to `Cargo.toml` in the current directory), and
+/// `--package <name>`/`-p <name>` selects a member when that manifest is a workspace root.
+/// Both the space-separated (`--flag value`) and `=`-joined (`--flag=value`) forms are
+/// accepted, matching `cargo`'s own flag parsing.
+fn parse_args() -> Result<Args, Error> {
+    let mut manifest_path = PathBuf::from("Cargo.toml");
+    let mut package = None;
+    let mut target_arch = None;
+
+    let mut args = env::args().skip(1).peekable();
+    if args.peek().map(String::as_str) == Some("generate-rpm") {
+        args.next();
+    }
+    while let Some(arg) = args.next() {
+        let (flag, inline_value) = match arg.split_once('=') {
+            Some((flag, value)) => (flag, Some(value.to_owned())),
+            None => (arg.as_str(), None),
+        };
+        match flag {
+            "--manifest-path" => {
+                manifest_path = PathBuf::from(_flag_value(flag, inline_value, &mut args)?);
+            }
+            "--package" | "-p" => {
+                package = Some(_flag_value(flag, inline_value, &mut args)?);
+            }
+            "--target" => {
+                target_arch = Some(_flag_value(flag, inline_value, &mut args)?);
+            }
+            _ => {}
+        }
+    }
+
+    Ok(Args {
+        manifest_path,
+        package,
+        target_arch,
+    })
+}
+
+/// Returns `inline_value` (from a `--flag=value` argument) if present, otherwise consumes
+/// the next argument as the value for a space-separated `--flag value`. Errors instead of
+/// panicking when the flag is the last argument and has no value.
+fn _flag_value(
+    flag: &str,
+    inline_value: Option<String>,
+    args: &mut std::iter::Peekable<impl Iterator<Item = String>>,
+) -> Result<String, Error> {
+    match inline_value {
+        Some(value) => Ok(value),
+        None => args.next().ok_or_else(|| {
+            Error::Config(ConfigError::Missing(match flag {
+                "--manifest-path" => "--manifest-path value",
+                "--package" | "-p" => "--package value",
+                "--target" => "--target value",
+                _ => "flag value",
+            }))
+        }),
+    }
+}
+
+fn run() -> Result<(), Error> {
+    let args = parse_args()?;
+    let config = Config::new(&args.manifest_path, args.package.as_deref())?;
+    let pkg = config.create_rpm_builder(args.target_arch)?.build()?;
+
+    let target_dir = args
+        .manifest_path
+        .parent()
+        .unwrap_or_else(|| Path::new("."))
+        .join("target/generate-rpm");
+    fs::create_dir_all(&target_dir)?;
+
+    let out_path = target_dir.join(format!("{}.rpm", pkg.metadata.get_name()?));
+    let mut file = File::create(&out_path)?;
+    pkg.write(&mut file)?;
+
+    println!("wrote {}", out_path.display());
+    Ok(())
+}